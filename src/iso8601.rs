@@ -0,0 +1,226 @@
+//! ISO 8601 duration strings (`PnDTnHnMnS`), the interchange format used by
+//! schedulers and calendar/JSON-Schema tooling.
+
+use std::fmt::Write;
+
+use crate::{
+    GoDuration, GoDurationParseError, NANOS_PER_DAY, NANOS_PER_HOUR, NANOS_PER_MINUTE,
+    NANOS_PER_SECOND,
+};
+
+/// Formats `value` as an ISO 8601 duration string, e.g. `PT1H30M`.
+///
+/// Decomposition deliberately stops at days, since weeks/months/years
+/// aren't fixed-length and would be lossy.
+pub fn to_string(value: &GoDuration) -> String {
+    if value.0 == 0 {
+        return "PT0S".to_string();
+    }
+
+    let negative = value.0.is_negative();
+    let mut nanos = value.0.unsigned_abs();
+
+    let days = nanos / NANOS_PER_DAY;
+    nanos %= NANOS_PER_DAY;
+    let hours = nanos / NANOS_PER_HOUR;
+    nanos %= NANOS_PER_HOUR;
+    let minutes = nanos / NANOS_PER_MINUTE;
+    nanos %= NANOS_PER_MINUTE;
+    let seconds = nanos / NANOS_PER_SECOND;
+    let sub_nanos = nanos % NANOS_PER_SECOND;
+
+    let mut out = String::new();
+    if negative {
+        out.push('-');
+    }
+    out.push('P');
+    if days > 0 {
+        write!(out, "{days}D").unwrap();
+    }
+
+    if hours > 0 || minutes > 0 || seconds > 0 || sub_nanos > 0 {
+        out.push('T');
+        if hours > 0 {
+            write!(out, "{hours}H").unwrap();
+        }
+        if minutes > 0 {
+            write!(out, "{minutes}M").unwrap();
+        }
+        if seconds > 0 || sub_nanos > 0 {
+            if sub_nanos > 0 {
+                let frac = format!("{sub_nanos:09}");
+                write!(out, "{seconds}.{}S", frac.trim_end_matches('0')).unwrap();
+            } else {
+                write!(out, "{seconds}S").unwrap();
+            }
+        }
+    }
+
+    out
+}
+
+/// Parses an ISO 8601 duration string such as `P1DT2H0M0S` into a
+/// `GoDuration`, saturating at [`GoDuration::MIN`]/[`GoDuration::MAX`].
+pub fn parse(input: &str) -> Result<GoDuration, GoDurationParseError> {
+    let (negative, input) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input),
+    };
+
+    let input = input
+        .strip_prefix('P')
+        .ok_or(GoDurationParseError::InvalidDuration)?;
+
+    let (date_part, time_part) = match input.split_once('T') {
+        Some((date, time)) => (date, time),
+        None => (input, ""),
+    };
+
+    if date_part.is_empty() && time_part.is_empty() {
+        return Err(GoDurationParseError::InvalidDuration);
+    }
+
+    let (day_nanos, date_rest) = take_designator(date_part, 'D', NANOS_PER_DAY)?;
+    if !date_rest.is_empty() {
+        return Err(GoDurationParseError::InvalidDuration);
+    }
+
+    let (hour_nanos, rest) = take_designator(time_part, 'H', NANOS_PER_HOUR)?;
+    let (minute_nanos, rest) = take_designator(rest, 'M', NANOS_PER_MINUTE)?;
+    let (second_nanos, rest) = take_designator(rest, 'S', NANOS_PER_SECOND)?;
+    if !rest.is_empty() {
+        return Err(GoDurationParseError::InvalidDuration);
+    }
+
+    let total = day_nanos
+        .saturating_add(hour_nanos)
+        .saturating_add(minute_nanos)
+        .saturating_add(second_nanos);
+
+    let nanos = if negative {
+        0i64.checked_sub_unsigned(total).unwrap_or(i64::MIN)
+    } else {
+        i64::try_from(total).unwrap_or(i64::MAX)
+    };
+    Ok(GoDuration(nanos))
+}
+
+/// Consumes a leading `n[.n]<designator>` magnitude from `input`, returning
+/// its value in nanoseconds and the unconsumed remainder. If `input` doesn't
+/// start with a digit, or the digits aren't followed by `designator`, the
+/// designator is treated as absent and `input` is returned unchanged.
+fn take_designator(
+    input: &str,
+    designator: char,
+    nanos_per_unit: u64,
+) -> Result<(u64, &str), GoDurationParseError> {
+    if !input.starts_with(|c: char| c.is_ascii_digit()) {
+        return Ok((0, input));
+    }
+
+    let int_end = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (int_part, after_int) = input.split_at(int_end);
+
+    let (frac_part, after_frac) = match after_int.strip_prefix('.') {
+        Some(rest) => {
+            let frac_end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            rest.split_at(frac_end)
+        }
+        None => ("", after_int),
+    };
+
+    if !after_frac.starts_with(designator) {
+        return Ok((0, input));
+    }
+    let rest = &after_frac[designator.len_utf8()..];
+
+    let whole: u64 = int_part
+        .parse()
+        .map_err(|_| GoDurationParseError::InvalidDuration)?;
+    let mut nanos = whole.saturating_mul(nanos_per_unit);
+
+    if !frac_part.is_empty() {
+        let mut scale = nanos_per_unit as f64;
+        let mut frac_nanos = 0.0;
+        for c in frac_part.chars() {
+            scale /= 10.0;
+            frac_nanos += scale * c.to_digit(10).unwrap() as f64;
+        }
+        nanos = nanos.saturating_add(frac_nanos as u64);
+    }
+
+    Ok((nanos, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_string() {
+        let cases = [
+            (GoDuration::ZERO, "PT0S"),
+            (GoDuration(90 * NANOS_PER_MINUTE as i64), "PT1H30M"),
+            (
+                GoDuration(NANOS_PER_DAY as i64 + 2 * NANOS_PER_HOUR as i64),
+                "P1DT2H",
+            ),
+            (GoDuration(NANOS_PER_DAY as i64), "P1D"),
+            (GoDuration(NANOS_PER_SECOND as i64 + 500_000_000), "PT1.5S"),
+            (GoDuration(-(90 * NANOS_PER_MINUTE as i64)), "-PT1H30M"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(expected, to_string(&input), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_valid() {
+        let cases = [
+            ("PT0S", 0),
+            ("PT1H30M", 90 * NANOS_PER_MINUTE as i64),
+            (
+                "P1DT2H0M0S",
+                NANOS_PER_DAY as i64 + 2 * NANOS_PER_HOUR as i64,
+            ),
+            ("P1D", NANOS_PER_DAY as i64),
+            ("PT1.5S", NANOS_PER_SECOND as i64 + 500_000_000),
+            ("-PT1H30M", -(90 * NANOS_PER_MINUTE as i64)),
+        ];
+
+        for (input, expected) in cases {
+            let output = parse(input).expect(input);
+            assert_eq!(GoDuration(expected), output, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let cases = ["", "1H30M", "PT1X", "P"];
+        for input in cases {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let cases = [
+            GoDuration::ZERO,
+            GoDuration(1),
+            GoDuration(-1),
+            GoDuration(90 * NANOS_PER_MINUTE as i64),
+            GoDuration::MIN,
+            GoDuration::MAX,
+        ];
+
+        for dur in cases {
+            let output = parse(&to_string(&dur)).expect("roundtrip");
+            assert_eq!(dur, output, "{dur:?}");
+        }
+    }
+}
@@ -1,13 +1,15 @@
 use std::{
     fmt::{self, Write},
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
     str::FromStr,
 };
 
-use ::nom::{Parser, Finish};
-#[cfg(feature = "serde")]
-use ::serde::{Deserialize, Serialize};
+use ::nom::{Finish, Parser};
 
+pub mod iso8601;
 pub mod nom;
+#[cfg(feature = "prost")]
+pub mod prost;
 #[cfg(feature = "serde")]
 pub mod serde;
 
@@ -19,9 +21,38 @@ pub enum GoDurationParseError {
     MissingUnit,
     #[error("time: unknown unit \"{0}\" in duration")]
     UnknownUnit(String),
+    #[error("time: expected number before unit")]
+    NumberExpected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("duration value out of range")]
+pub struct GoDurationConversionError;
+
+/// A fixed unit to render a [`GoDuration`] in via [`GoDuration::format_fixed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Nanos,
+    Micros,
+    Millis,
+    Seconds,
+    Minutes,
+    Hours,
+}
+
+impl Unit {
+    fn nanos_per_unit(self) -> u64 {
+        match self {
+            Unit::Nanos => 1,
+            Unit::Micros => NANOS_PER_MICROSECOND,
+            Unit::Millis => NANOS_PER_MILLISECOND,
+            Unit::Seconds => NANOS_PER_SECOND,
+            Unit::Minutes => NANOS_PER_MINUTE,
+            Unit::Hours => NANOS_PER_HOUR,
+        }
+    }
 }
 
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct GoDuration(
     /// nanoseconds
@@ -38,9 +69,164 @@ impl GoDuration {
         self.0
     }
 
+    pub fn microseconds(&self) -> i64 {
+        self.0 / NANOS_PER_MICROSECOND as i64
+    }
+
+    pub fn milliseconds(&self) -> i64 {
+        self.0 / NANOS_PER_MILLISECOND as i64
+    }
+
+    pub fn seconds(&self) -> f64 {
+        let whole = self.0 / NANOS_PER_SECOND as i64;
+        let rem = self.0 % NANOS_PER_SECOND as i64;
+        whole as f64 + rem as f64 / NANOS_PER_SECOND as f64
+    }
+
+    pub fn minutes(&self) -> f64 {
+        let whole = self.0 / NANOS_PER_MINUTE as i64;
+        let rem = self.0 % NANOS_PER_MINUTE as i64;
+        whole as f64 + rem as f64 / NANOS_PER_MINUTE as f64
+    }
+
+    pub fn hours(&self) -> f64 {
+        let whole = self.0 / NANOS_PER_HOUR as i64;
+        let rem = self.0 % NANOS_PER_HOUR as i64;
+        whole as f64 + rem as f64 / NANOS_PER_HOUR as f64
+    }
+
+    /// Renders this duration in a fixed `unit` with a fixed number of
+    /// fractional `decimals`, e.g. `format_fixed(Unit::Seconds, 9)` ->
+    /// `"0.000004000"`. Unlike [`Display`](fmt::Display), the unit and
+    /// decimal width never vary, which makes columns of output alignable.
+    pub fn format_fixed(&self, unit: Unit, decimals: usize) -> String {
+        let scale = unit.nanos_per_unit() as u128;
+        let pow10 = 10u128.checked_pow(decimals as u32).unwrap_or(u128::MAX);
+        let scaled = (self.0.unsigned_abs() as u128).saturating_mul(pow10) / scale;
+
+        let digits = scaled.to_string();
+        let digits = format!("{digits:0>width$}", width = decimals + 1);
+        let (int_part, frac_part) = digits.split_at(digits.len() - decimals);
+
+        let sign = if self.0.is_negative() { "-" } else { "" };
+        if decimals == 0 {
+            format!("{sign}{int_part}")
+        } else {
+            format!("{sign}{int_part}.{frac_part}")
+        }
+    }
+
     pub fn abs(&self) -> Self {
         Self(0i64.saturating_add_unsigned(self.0.unsigned_abs()))
     }
+
+    /// Rounds toward zero to the nearest multiple of `m`. Returns `self`
+    /// unchanged if `m <= 0`, matching Go's `time.Duration.Truncate`.
+    pub fn truncate(&self, m: Self) -> Self {
+        if m.0 <= 0 {
+            return *self;
+        }
+        Self(self.0 - self.0 % m.0)
+    }
+
+    /// Rounds to the nearest multiple of `m`, ties away from zero. Returns
+    /// `self` unchanged if `m <= 0`, matching Go's `time.Duration.Round`.
+    pub fn round(&self, m: Self) -> Self {
+        if m.0 <= 0 {
+            return *self;
+        }
+        let remainder = self.0 % m.0;
+        let truncated = self.0 - remainder;
+        if remainder
+            .unsigned_abs()
+            .saturating_add(remainder.unsigned_abs())
+            < m.0 as u64
+        {
+            return Self(truncated);
+        }
+        if self.0 >= 0 {
+            Self(truncated.saturating_add(m.0))
+        } else {
+            Self(truncated.saturating_sub(m.0))
+        }
+    }
+
+    pub fn checked_add(&self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    pub fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    pub fn checked_mul(&self, rhs: i64) -> Option<Self> {
+        self.0.checked_mul(rhs).map(Self)
+    }
+
+    pub fn saturating_add(&self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    pub fn saturating_sub(&self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    pub fn saturating_mul(&self, rhs: i64) -> Self {
+        Self(self.0.saturating_mul(rhs))
+    }
+}
+
+impl Add for GoDuration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for GoDuration {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Neg for GoDuration {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        // mirrors `abs()`: negating `MIN` would overflow, so saturate at `MAX` instead
+        Self(0i64.checked_sub(self.0).unwrap_or(i64::MAX))
+    }
+}
+
+impl Mul<i64> for GoDuration {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Div<i64> for GoDuration {
+    type Output = Self;
+
+    fn div(self, rhs: i64) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+impl AddAssign for GoDuration {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for GoDuration {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
 }
 
 impl TryFrom<&str> for GoDuration {
@@ -57,6 +243,64 @@ impl From<i64> for GoDuration {
     }
 }
 
+impl TryFrom<std::time::Duration> for GoDuration {
+    type Error = GoDurationConversionError;
+
+    fn try_from(value: std::time::Duration) -> Result<Self, Self::Error> {
+        let nanos =
+            value.as_secs() as u128 * NANOS_PER_SECOND as u128 + value.subsec_nanos() as u128;
+        i64::try_from(nanos)
+            .map(Self)
+            .map_err(|_| GoDurationConversionError)
+    }
+}
+
+impl TryFrom<GoDuration> for std::time::Duration {
+    type Error = GoDurationConversionError;
+
+    fn try_from(value: GoDuration) -> Result<Self, Self::Error> {
+        let nanos = u64::try_from(value.0).map_err(|_| GoDurationConversionError)?;
+        Ok(std::time::Duration::from_nanos(nanos))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<GoDuration> for chrono::Duration {
+    fn from(value: GoDuration) -> Self {
+        chrono::Duration::nanoseconds(value.0)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::Duration> for GoDuration {
+    type Error = GoDurationConversionError;
+
+    fn try_from(value: chrono::Duration) -> Result<Self, Self::Error> {
+        value
+            .num_nanoseconds()
+            .map(Self)
+            .ok_or(GoDurationConversionError)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<GoDuration> for time::Duration {
+    fn from(value: GoDuration) -> Self {
+        time::Duration::nanoseconds(value.0)
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::Duration> for GoDuration {
+    type Error = GoDurationConversionError;
+
+    fn try_from(value: time::Duration) -> Result<Self, Self::Error> {
+        i64::try_from(value.whole_nanoseconds())
+            .map(Self)
+            .map_err(|_| GoDurationConversionError)
+    }
+}
+
 impl fmt::Display for GoDuration {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> fmt::Result {
         let nanos = self.0;
@@ -111,11 +355,27 @@ pub(crate) const NANOS_PER_MILLISECOND: u64 = 1_000_000;
 pub(crate) const NANOS_PER_SECOND: u64 = 1_000_000_000;
 pub(crate) const NANOS_PER_MINUTE: u64 = NANOS_PER_SECOND * 60;
 pub(crate) const NANOS_PER_HOUR: u64 = NANOS_PER_MINUTE * 60;
+pub(crate) const NANOS_PER_DAY: u64 = NANOS_PER_HOUR * 24;
+pub(crate) const NANOS_PER_WEEK: u64 = NANOS_PER_DAY * 7;
+pub(crate) const NANOS_PER_MONTH: u64 = NANOS_PER_DAY * 30;
+pub(crate) const NANOS_PER_YEAR: u64 = NANOS_PER_DAY * 365;
 
 pub fn parse_go_duration(input: &str) -> Result<GoDuration, GoDurationParseError> {
     nom::go_duration.parse(input).finish().map(|(_, dur)| dur)
 }
 
+/// Parses a "humanized" duration string such as `1h 30m` or `2 days 4 hours`.
+///
+/// Unlike [`parse_go_duration`], this accepts whitespace between components
+/// and a wider set of units (`d`/`day(s)`, `w`/`week(s)`, `month(s)`,
+/// `y`/`year(s)`), following humantime's fold-and-accumulate semantics.
+pub fn parse_humanized(input: &str) -> Result<GoDuration, GoDurationParseError> {
+    nom::go_duration_humanized
+        .parse(input)
+        .finish()
+        .map(|(_, dur)| dur)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,4 +520,144 @@ mod tests {
             assert_eq!(expected, output.nanoseconds(), "{input}");
         }
     }
+
+    #[test]
+    fn test_operator_impls() {
+        assert_eq!(GoDuration(3), GoDuration(1) + GoDuration(2));
+        assert_eq!(GoDuration(-1), GoDuration(1) - GoDuration(2));
+        assert_eq!(GoDuration(-5), -GoDuration(5));
+        assert_eq!(GoDuration::MAX, -GoDuration::MIN);
+        assert_eq!(GoDuration(10), GoDuration(5) * 2);
+        assert_eq!(GoDuration(5), GoDuration(10) / 2);
+
+        let mut dur = GoDuration(1);
+        dur += GoDuration(2);
+        assert_eq!(GoDuration(3), dur);
+        dur -= GoDuration(1);
+        assert_eq!(GoDuration(2), dur);
+    }
+
+    #[test]
+    fn test_checked_math() {
+        assert_eq!(
+            Some(GoDuration(3)),
+            GoDuration(1).checked_add(GoDuration(2))
+        );
+        assert_eq!(None, GoDuration::MAX.checked_add(GoDuration(1)));
+        assert_eq!(
+            Some(GoDuration(1)),
+            GoDuration(3).checked_sub(GoDuration(2))
+        );
+        assert_eq!(None, GoDuration::MIN.checked_sub(GoDuration(1)));
+        assert_eq!(Some(GoDuration(10)), GoDuration(5).checked_mul(2));
+        assert_eq!(None, GoDuration::MAX.checked_mul(2));
+    }
+
+    #[test]
+    fn test_saturating_math() {
+        assert_eq!(
+            GoDuration::MAX,
+            GoDuration::MAX.saturating_add(GoDuration(1))
+        );
+        assert_eq!(
+            GoDuration::MIN,
+            GoDuration::MIN.saturating_sub(GoDuration(1))
+        );
+        assert_eq!(GoDuration::MAX, GoDuration::MAX.saturating_mul(2));
+        assert_eq!(GoDuration::MIN, GoDuration::MIN.saturating_mul(2));
+        assert_eq!(GoDuration(10), GoDuration(5).saturating_mul(2));
+    }
+
+    #[test]
+    fn test_try_from_std_duration() {
+        let output = GoDuration::try_from(std::time::Duration::new(1, 500));
+        assert_eq!(Ok(GoDuration(1_000_000_500)), output);
+
+        let output = GoDuration::try_from(std::time::Duration::new(u64::MAX, 0));
+        assert_eq!(Err(GoDurationConversionError), output);
+    }
+
+    #[test]
+    fn test_try_into_std_duration() {
+        let output = std::time::Duration::try_from(GoDuration(1_000_000_500));
+        assert_eq!(Ok(std::time::Duration::new(1, 500)), output);
+
+        let output = std::time::Duration::try_from(GoDuration(-1));
+        assert_eq!(Err(GoDurationConversionError), output);
+    }
+
+    #[test]
+    fn test_unit_accessors() {
+        let dur = GoDuration(NANOS_PER_HOUR as i64 + NANOS_PER_MILLISECOND as i64);
+        assert_eq!(3_600, dur.seconds() as i64);
+        assert_eq!(60, dur.minutes() as i64);
+        assert_eq!(1, dur.hours() as i64);
+        assert_eq!(3_600_001, dur.milliseconds());
+        assert_eq!(3_600_001_000, dur.microseconds());
+    }
+
+    #[test]
+    fn test_truncate() {
+        let cases = [
+            (
+                NANOS_PER_SECOND as i64 * 3 / 2,
+                NANOS_PER_SECOND as i64,
+                NANOS_PER_SECOND as i64,
+            ),
+            (
+                -(NANOS_PER_SECOND as i64 * 3 / 2),
+                NANOS_PER_SECOND as i64,
+                -(NANOS_PER_SECOND as i64),
+            ),
+            (42, 0, 42),
+        ];
+
+        for (input, m, expected) in cases {
+            let output = GoDuration(input).truncate(GoDuration(m));
+            assert_eq!(GoDuration(expected), output, "{input} {m}");
+        }
+    }
+
+    #[test]
+    fn test_round() {
+        let cases = [
+            (
+                NANOS_PER_SECOND as i64 * 3 / 2,
+                NANOS_PER_SECOND as i64,
+                2 * NANOS_PER_SECOND as i64,
+            ),
+            (
+                NANOS_PER_SECOND as i64 * 5 / 4,
+                NANOS_PER_SECOND as i64,
+                NANOS_PER_SECOND as i64,
+            ),
+            (
+                -(NANOS_PER_SECOND as i64 * 3 / 2),
+                NANOS_PER_SECOND as i64,
+                -2 * NANOS_PER_SECOND as i64,
+            ),
+            (42, 0, 42),
+        ];
+
+        for (input, m, expected) in cases {
+            let output = GoDuration(input).round(GoDuration(m));
+            assert_eq!(GoDuration(expected), output, "{input} {m}");
+        }
+    }
+
+    #[test]
+    fn test_format_fixed() {
+        let cases = [
+            (4000, Unit::Seconds, 9, "0.000004000"),
+            (4000, Unit::Micros, 0, "4"),
+            (-4000, Unit::Micros, 0, "-4"),
+            (0, Unit::Seconds, 3, "0.000"),
+            (NANOS_PER_HOUR as i64, Unit::Hours, 2, "1.00"),
+        ];
+
+        for (input, unit, decimals, expected) in cases {
+            let output = GoDuration(input).format_fixed(unit, decimals);
+            assert_eq!(expected, output, "{input} {decimals}");
+        }
+    }
 }
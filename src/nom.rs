@@ -1,7 +1,7 @@
 use ::nom::{
     branch::alt,
     bytes::complete::{tag, take_till},
-    character::complete::{char, digit0, digit1},
+    character::complete::{char, digit0, digit1, multispace0},
     combinator::{all_consuming, cut, map_res, opt, value},
     error::{FromExternalError, ParseError},
     multi::fold_many1,
@@ -10,8 +10,9 @@ use ::nom::{
 };
 
 use crate::{
-    GoDuration, GoDurationParseError, NANOS_PER_HOUR, NANOS_PER_MICROSECOND, NANOS_PER_MILLISECOND,
-    NANOS_PER_MINUTE, NANOS_PER_SECOND,
+    GoDuration, GoDurationParseError, NANOS_PER_DAY, NANOS_PER_HOUR, NANOS_PER_MICROSECOND,
+    NANOS_PER_MILLISECOND, NANOS_PER_MINUTE, NANOS_PER_MONTH, NANOS_PER_SECOND, NANOS_PER_WEEK,
+    NANOS_PER_YEAR,
 };
 
 impl<I> ParseError<I> for GoDurationParseError {
@@ -95,6 +96,61 @@ pub fn go_duration(input: &str) -> IResult<&str, GoDuration, GoDurationParseErro
     Ok((input, GoDuration(nanos)))
 }
 
+fn humanized_unit(input: &str) -> IResult<&str, u64, GoDurationParseError> {
+    let (input, unit) =
+        take_till(|c: char| c.is_ascii_digit() || c == '.' || c.is_ascii_whitespace())(input)?;
+    if unit.is_empty() {
+        return Err(NomErr::Error(GoDurationParseError::NumberExpected));
+    }
+    let scale = match unit {
+        "ns" | "nsec" => 1,
+        "us" | "\u{00B5}s" | "\u{03BC}s" | "usec" => NANOS_PER_MICROSECOND,
+        "ms" | "msec" => NANOS_PER_MILLISECOND,
+        "s" | "sec" | "second" | "seconds" => NANOS_PER_SECOND,
+        "m" | "min" | "minute" | "minutes" => NANOS_PER_MINUTE,
+        "h" | "hr" | "hour" | "hours" => NANOS_PER_HOUR,
+        "d" | "day" | "days" => NANOS_PER_DAY,
+        "w" | "week" | "weeks" => NANOS_PER_WEEK,
+        "month" | "months" => NANOS_PER_MONTH,
+        "y" | "year" | "years" => NANOS_PER_YEAR,
+        _ => {
+            return Err(NomErr::Error(GoDurationParseError::UnknownUnit(
+                unit.to_string(),
+            )))
+        }
+    };
+    Ok((input, scale))
+}
+
+pub fn go_duration_humanized(input: &str) -> IResult<&str, GoDuration, GoDurationParseError> {
+    let (input, sign) = sign(input)?;
+    let (input, nanos) = fold_many1(
+        preceded(
+            multispace0,
+            pair(
+                map_res(digit1, str::parse::<u64>),
+                preceded(multispace0, cut(humanized_unit)),
+            ),
+        ),
+        || 0u64,
+        |acc, (n, scale)| acc.saturating_add(n.saturating_mul(scale)),
+    )
+    .parse(input)?;
+
+    let input = input.trim_start();
+    if !input.is_empty() {
+        return Err(NomErr::Error(GoDurationParseError::NumberExpected));
+    }
+
+    let nanos = if sign {
+        i64::try_from(nanos).map_err(|_| NomErr::Error(GoDurationParseError::InvalidDuration))?
+    } else {
+        0i64.checked_sub_unsigned(nanos)
+            .ok_or(NomErr::Error(GoDurationParseError::InvalidDuration))?
+    };
+    Ok((input, GoDuration(nanos)))
+}
+
 #[cfg(test)]
 mod tests {
     use nom::{combinator::map_res, Finish};
@@ -112,4 +168,48 @@ mod tests {
         let output: GoDurationParseError = output.unwrap_err();
         assert_eq!(output, GoDurationParseError::InvalidDuration);
     }
+
+    #[test]
+    fn test_humanized_valid() {
+        let cases = [
+            ("1h30m", 90 * NANOS_PER_MINUTE as i64),
+            ("1h 30m", 90 * NANOS_PER_MINUTE as i64),
+            (
+                "2 days 4 hours",
+                2 * NANOS_PER_DAY as i64 + 4 * NANOS_PER_HOUR as i64,
+            ),
+            ("1 week", NANOS_PER_WEEK as i64),
+            ("1 month", NANOS_PER_MONTH as i64),
+            ("1 year", NANOS_PER_YEAR as i64),
+            ("-1h 30m", -90 * NANOS_PER_MINUTE as i64),
+            ("1nsec", 1),
+            ("1usec", NANOS_PER_MICROSECOND as i64),
+            ("1msec", NANOS_PER_MILLISECOND as i64),
+            ("1sec", NANOS_PER_SECOND as i64),
+        ];
+
+        for (input, expected) in cases {
+            let output = go_duration_humanized(input).finish().expect(input).1;
+            assert_eq!(GoDuration(expected), output, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_humanized_invalid() {
+        let cases = [
+            ("30", GoDurationParseError::NumberExpected),
+            ("1h 30", GoDurationParseError::NumberExpected),
+            (
+                "1fortnight",
+                GoDurationParseError::UnknownUnit("fortnight".to_string()),
+            ),
+            ("", GoDurationParseError::InvalidDuration),
+        ];
+
+        for (input, expected) in cases {
+            let output = go_duration_humanized(input).finish();
+            assert!(output.is_err(), "{input}");
+            assert_eq!(expected, output.unwrap_err(), "{input}");
+        }
+    }
 }
@@ -0,0 +1,108 @@
+use crate::{GoDuration, NANOS_PER_SECOND};
+
+const NANOS_PER_SECOND_I32: i32 = NANOS_PER_SECOND as i32;
+
+impl GoDuration {
+    /// Builds a `GoDuration` from a protobuf `google.protobuf.Duration`
+    /// `{ seconds, nanos }` pair, normalizing it to canonical form first and
+    /// saturating at [`GoDuration::MIN`]/[`GoDuration::MAX`].
+    pub fn from_seconds_nanos(seconds: i64, nanos: i32) -> Self {
+        let (seconds, nanos) = normalize(seconds, nanos);
+        let total = seconds as i128 * NANOS_PER_SECOND as i128 + nanos as i128;
+        Self(total.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+
+    /// Splits this duration into a protobuf `google.protobuf.Duration`
+    /// `{ seconds, nanos }` pair. The result is already in canonical form.
+    pub fn to_seconds_nanos(&self) -> (i64, i32) {
+        let seconds = self.0 / NANOS_PER_SECOND as i64;
+        let nanos = self.0 % NANOS_PER_SECOND as i64;
+        (seconds, nanos as i32)
+    }
+}
+
+/// Applies the protobuf canonical-form rules for `google.protobuf.Duration`:
+/// `nanos` is folded back into `seconds` if it overflows one second, and the
+/// two fields are made to agree in sign (or one of them is zero).
+fn normalize(mut seconds: i64, mut nanos: i32) -> (i64, i32) {
+    if nanos <= -NANOS_PER_SECOND_I32 || nanos >= NANOS_PER_SECOND_I32 {
+        let carry = nanos / NANOS_PER_SECOND_I32;
+        seconds = seconds.saturating_add(carry as i64);
+        nanos -= carry * NANOS_PER_SECOND_I32;
+    }
+    if seconds > 0 && nanos < 0 {
+        seconds -= 1;
+        nanos += NANOS_PER_SECOND_I32;
+    } else if seconds < 0 && nanos > 0 {
+        seconds += 1;
+        nanos -= NANOS_PER_SECOND_I32;
+    }
+    (seconds, nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_seconds_nanos() {
+        let cases = [
+            ((1, 0), 1_000_000_000),
+            ((0, 500), 500),
+            ((1, 500_000_000), 1_500_000_000),
+            ((-1, 0), -1_000_000_000),
+            ((-1, -500_000_000), -1_500_000_000),
+            // out-of-range nanos get carried into seconds
+            ((0, 1_500_000_000), 1_500_000_000),
+            ((0, -1_500_000_000), -1_500_000_000),
+            // mismatched signs get normalized
+            ((1, -500_000_000), 500_000_000),
+            ((-1, 500_000_000), -500_000_000),
+        ];
+
+        for ((seconds, nanos), expected) in cases {
+            let output = GoDuration::from_seconds_nanos(seconds, nanos);
+            assert_eq!(GoDuration(expected), output, "{seconds} {nanos}");
+        }
+    }
+
+    #[test]
+    fn test_from_seconds_nanos_saturates() {
+        let output = GoDuration::from_seconds_nanos(i64::MAX, 999_999_999);
+        assert_eq!(GoDuration::MAX, output);
+
+        let output = GoDuration::from_seconds_nanos(i64::MIN, -999_999_999);
+        assert_eq!(GoDuration::MIN, output);
+    }
+
+    #[test]
+    fn test_to_seconds_nanos() {
+        let cases = [
+            (1_000_000_000, (1, 0)),
+            (1_500_000_000, (1, 500_000_000)),
+            (-1_500_000_000, (-1, -500_000_000)),
+            (500, (0, 500)),
+            (-500, (0, -500)),
+        ];
+
+        for (nanos, expected) in cases {
+            let output = GoDuration(nanos).to_seconds_nanos();
+            assert_eq!(expected, output, "{nanos}");
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let cases = [
+            GoDuration(0),
+            GoDuration(1),
+            GoDuration(-1),
+            GoDuration::MIN,
+            GoDuration::MAX,
+        ];
+        for dur in cases {
+            let (seconds, nanos) = dur.to_seconds_nanos();
+            assert_eq!(dur, GoDuration::from_seconds_nanos(seconds, nanos));
+        }
+    }
+}
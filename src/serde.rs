@@ -73,12 +73,155 @@ pub mod nanoseconds {
     }
 }
 
+/// Clock-style `HH:MM:SS.mmm` strings, as used by several media/OBS-style
+/// APIs.
+///
+/// Both [`strict`] and [`flexible`] serialize the same way; they differ in
+/// how forgiving `deserialize` is about the input. Pick one via
+/// `#[serde(with = "go_duration::serde::clock::strict")]`.
+pub mod clock {
+    use crate::{
+        GoDuration, GoDurationParseError, NANOS_PER_HOUR, NANOS_PER_MILLISECOND, NANOS_PER_MINUTE,
+        NANOS_PER_SECOND,
+    };
+
+    fn to_clock_string(value: &GoDuration) -> String {
+        let mut nanos = value.0.unsigned_abs();
+        let hours = nanos / NANOS_PER_HOUR;
+        nanos %= NANOS_PER_HOUR;
+        let minutes = nanos / NANOS_PER_MINUTE;
+        nanos %= NANOS_PER_MINUTE;
+        let seconds = nanos / NANOS_PER_SECOND;
+        nanos %= NANOS_PER_SECOND;
+        let millis = nanos / NANOS_PER_MILLISECOND;
+
+        let sign = if value.0.is_negative() { "-" } else { "" };
+        format!("{sign}{hours:02}:{minutes:02}:{seconds:02}.{millis:03}")
+    }
+
+    fn parse_component(s: &str) -> Result<u64, GoDurationParseError> {
+        s.parse().map_err(|_| GoDurationParseError::InvalidDuration)
+    }
+
+    /// Parses an unsigned, possibly truncated fractional-seconds string into
+    /// milliseconds, e.g. `"5"` -> `500`, `"25"` -> `250`.
+    fn parse_millis(frac: &str) -> Result<u64, GoDurationParseError> {
+        if frac.is_empty() || !frac.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(GoDurationParseError::InvalidDuration);
+        }
+        let mut digits = [b'0'; 3];
+        for (slot, b) in digits.iter_mut().zip(frac.bytes()) {
+            *slot = b;
+        }
+        parse_component(std::str::from_utf8(&digits).unwrap())
+    }
+
+    fn from_clock_parts(
+        negative: bool,
+        hours: u64,
+        minutes: u64,
+        seconds: u64,
+        millis: u64,
+    ) -> Result<GoDuration, GoDurationParseError> {
+        let nanos = hours
+            .checked_mul(NANOS_PER_HOUR)
+            .zip(minutes.checked_mul(NANOS_PER_MINUTE))
+            .and_then(|(h, m)| h.checked_add(m))
+            .zip(seconds.checked_mul(NANOS_PER_SECOND))
+            .and_then(|(hm, s)| hm.checked_add(s))
+            .zip(millis.checked_mul(NANOS_PER_MILLISECOND))
+            .and_then(|(hms, ms)| hms.checked_add(ms))
+            .ok_or(GoDurationParseError::InvalidDuration)?;
+
+        let nanos = i64::try_from(nanos).map_err(|_| GoDurationParseError::InvalidDuration)?;
+        Ok(GoDuration(if negative { -nanos } else { nanos }))
+    }
+
+    fn parse_clock(s: &str, flexible: bool) -> Result<GoDuration, GoDurationParseError> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        let (whole, frac) = match s.split_once('.') {
+            Some((whole, frac)) => (whole, Some(frac)),
+            None => (s, None),
+        };
+
+        let parts: Vec<&str> = whole.split(':').collect();
+        let (hours, minutes, seconds) = match (flexible, parts.as_slice()) {
+            (_, [h, m, s]) => (
+                parse_component(h)?,
+                parse_component(m)?,
+                parse_component(s)?,
+            ),
+            (true, [m, s]) => (0, parse_component(m)?, parse_component(s)?),
+            (true, [s]) => (0, 0, parse_component(s)?),
+            _ => return Err(GoDurationParseError::InvalidDuration),
+        };
+
+        let millis = match (flexible, frac) {
+            (true, Some(frac)) => parse_millis(frac)?,
+            (true, None) => 0,
+            (false, Some(frac)) if frac.len() == 3 => parse_millis(frac)?,
+            (false, _) => return Err(GoDurationParseError::InvalidDuration),
+        };
+
+        from_clock_parts(negative, hours, minutes, seconds, millis)
+    }
+
+    pub mod strict {
+        use ::serde::{de, de::Deserialize, ser};
+
+        use super::{parse_clock, to_clock_string};
+        use crate::GoDuration;
+
+        pub fn serialize<S>(value: &GoDuration, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_str(&to_clock_string(value))
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<GoDuration, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            parse_clock(&s, false).map_err(de::Error::custom)
+        }
+    }
+
+    pub mod flexible {
+        use ::serde::{de, de::Deserialize, ser};
+
+        use super::{parse_clock, to_clock_string};
+        use crate::GoDuration;
+
+        pub fn serialize<S>(value: &GoDuration, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_str(&to_clock_string(value))
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<GoDuration, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            parse_clock(&s, true).map_err(de::Error::custom)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde::{Deserialize, Serialize};
     use serde_test::{assert_de_tokens_error, assert_tokens, Token};
 
     use super::*;
+    use crate::{NANOS_PER_HOUR, NANOS_PER_MILLISECOND, NANOS_PER_MINUTE, NANOS_PER_SECOND};
 
     #[test]
     fn test_ser_de() {
@@ -121,6 +264,68 @@ mod tests {
         Ok(())
     }
 
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct GoDurationClockTest {
+        #[serde(with = "super::clock::strict")]
+        pub strict: GoDuration,
+        #[serde(with = "super::clock::flexible")]
+        pub flexible: GoDuration,
+    }
+
+    #[test]
+    fn test_clock_ser() {
+        let value = GoDurationClockTest {
+            strict: GoDuration(-(3 * NANOS_PER_HOUR as i64 + 61 * NANOS_PER_MILLISECOND as i64)),
+            flexible: GoDuration::ZERO,
+        };
+        let output = serde_json::to_string(&value).unwrap();
+        let expected = r#"{"strict":"-03:00:00.061","flexible":"00:00:00.000"}"#;
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_clock_strict_de() {
+        let value: GoDurationClockTest =
+            serde_json::from_str(r#"{"strict":"01:02:03.004","flexible":"01:02:03.004"}"#).unwrap();
+        assert_eq!(
+            GoDuration(
+                NANOS_PER_HOUR as i64
+                    + 2 * NANOS_PER_MINUTE as i64
+                    + 3 * NANOS_PER_SECOND as i64
+                    + 4 * NANOS_PER_MILLISECOND as i64
+            ),
+            value.strict,
+        );
+
+        let output = serde_json::from_str::<'_, GoDurationClockTest>(
+            r#"{"strict":"02:03.004","flexible":"02:03.004"}"#,
+        );
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn test_clock_flexible_de() {
+        let cases = [
+            ("90", 90 * NANOS_PER_SECOND as i64),
+            (
+                "02:03.5",
+                2 * NANOS_PER_MINUTE as i64
+                    + 3 * NANOS_PER_SECOND as i64
+                    + 500 * NANOS_PER_MILLISECOND as i64,
+            ),
+            (
+                "01:02:03",
+                NANOS_PER_HOUR as i64 + 2 * NANOS_PER_MINUTE as i64 + 3 * NANOS_PER_SECOND as i64,
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let json = format!(r#"{{"strict":"00:00:00.000","flexible":"{input}"}}"#);
+            let output: GoDurationClockTest = serde_json::from_str(&json).unwrap();
+            assert_eq!(GoDuration(expected), output.flexible, "{input}");
+        }
+    }
+
     #[test]
     fn test_json_de_error() {
         let output = serde_json::from_str::<'_, GoDurationTest>(r#"{"dur":11,"nanos":0}"#);